@@ -12,8 +12,10 @@
 //! It rely and uses the logging infrastructure in the [log][log] crate; and
 //! is fully compatible with any logger implementation.
 //!
-//! These macro will store the already seen messages in a `BTreeSet`, and check
-//! if a message is in the set before sending the log event.
+//! These macro will store a hash of the already seen messages in a set, and
+//! check if a message's hash is in the set before sending the log event.
+//! Enable the `exact-messages` feature to store the full messages instead,
+//! trading the (negligible) hash collision risk for extra memory use.
 //!
 //! [log]: https://crates.io/crates/log
 //!
@@ -55,24 +57,126 @@
 #[allow(unused_imports)]
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate lazy_static;
 pub use log::LogLevel;
 
+#[cfg(feature = "exact-messages")]
 use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
 use std::sync::{Mutex, MutexGuard, PoisonError};
 
+/// The key stored in the seen-set for each remembered message.
+///
+/// By default this is a 64-bit hash of the target/level/message, which
+/// costs 8 bytes per remembered event instead of an owned `String`, at the
+/// cost of a negligible collision risk (a collision would wrongly suppress
+/// a genuinely new message). Enable the `exact-messages` feature to store
+/// the full message instead and get exact, collision-free deduplication.
+#[cfg(not(feature = "exact-messages"))]
+type __SeenKey = u64;
+#[cfg(feature = "exact-messages")]
+type __SeenKey = String;
+
+#[cfg(not(feature = "exact-messages"))]
+type __SeenSet = ::std::collections::HashSet<__SeenKey>;
+#[cfg(feature = "exact-messages")]
+type __SeenSet = BTreeSet<__SeenKey>;
+
+/// Turns a rendered target/level/message string into the key actually
+/// stored in the seen-set, hashing it unless the `exact-messages` feature
+/// is enabled. See [`__SeenKey`](type.__SeenKey.html) for the trade-off.
+#[doc(hidden)]
+#[cfg(not(feature = "exact-messages"))]
+pub fn __seen_key(event: &str) -> __SeenKey {
+    let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+    event.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[doc(hidden)]
+#[cfg(feature = "exact-messages")]
+pub fn __seen_key(event: &str) -> __SeenKey {
+    event.to_string()
+}
+
+/// Duplicates a `__SeenKey` so it can be recorded in both the `order` deque
+/// and the `messages` set. A plain `.clone()` trips `clippy::clone_on_copy`
+/// when `__SeenKey` is the default `u64`, so this dereferences instead for
+/// that case and only clones the owned `String` used by `exact-messages`.
+#[cfg(not(feature = "exact-messages"))]
+fn __clone_key(event: &__SeenKey) -> __SeenKey {
+    *event
+}
+
+#[cfg(feature = "exact-messages")]
+fn __clone_key(event: &__SeenKey) -> __SeenKey {
+    event.clone()
+}
+
+/// A set of already-seen messages, with an optional maximum `capacity`.
+///
+/// When `capacity` is `None` (the default, used by [`log_once!`]), the set
+/// grows without bound, exactly like the plain `BTreeSet` this crate used
+/// to store directly. When `capacity` is `Some(n)`, inserting past `n`
+/// entries evicts the oldest remembered message (FIFO, tracked by the
+/// companion `order` deque) to make room for the new one; a message that
+/// gets evicted may be logged again if it reoccurs later.
+///
+/// [`log_once!`]: macro.log_once.html
+#[doc(hidden)]
+pub struct __BoundedSet {
+    messages: __SeenSet,
+    order: VecDeque<__SeenKey>,
+    capacity: Option<usize>,
+}
+
+impl __BoundedSet {
+    pub fn contains(&self, event: &__SeenKey) -> bool {
+        self.messages.contains(event)
+    }
+
+    pub fn insert(&mut self, event: __SeenKey) {
+        if self.capacity == Some(0) {
+            // A capacity of zero means "never remember anything": inserting
+            // would immediately need to be evicted, so skip it entirely
+            // instead of letting a message through before its eviction.
+            return;
+        }
+        if let Some(capacity) = self.capacity {
+            if self.messages.len() >= capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.messages.remove(&oldest);
+                }
+            }
+        }
+        self.order.push_back(__clone_key(&event));
+        self.messages.insert(event);
+    }
+}
+
 #[doc(hidden)]
 pub struct __MessagesSet {
-    inner: Mutex<BTreeSet<String>>
+    inner: Mutex<__BoundedSet>
 }
 
 impl __MessagesSet {
     pub fn new() -> __MessagesSet {
+        __MessagesSet::with_capacity(None)
+    }
+
+    pub fn with_capacity(capacity: Option<usize>) -> __MessagesSet {
         __MessagesSet {
-            inner: Mutex::new(BTreeSet::new())
+            inner: Mutex::new(__BoundedSet {
+                messages: __SeenSet::default(),
+                order: VecDeque::new(),
+                capacity,
+            })
         }
     }
 
-    pub fn lock(&self) -> Result<MutexGuard<BTreeSet<String>>, PoisonError<MutexGuard<BTreeSet<String>>>> {
+    pub fn lock(&self) -> Result<MutexGuard<__BoundedSet>, PoisonError<MutexGuard<__BoundedSet>>> {
         self.inner.lock()
     }
 }
@@ -84,6 +188,15 @@ impl __MessagesSet {
 /// This macro will generically log with the specified `LogLevel` and `format!`
 /// based argument list.
 ///
+/// An optional leading `key: ($expr, $expr, ...)` group can be used to
+/// deduplicate on a user-chosen subset of the arguments instead of the full
+/// rendered message: `warn_once!(key: (conn_id), "connection {} dropped: {}",
+/// conn_id, reason)` will only log once per distinct `conn_id`, regardless
+/// of how `reason` varies, because only the `key:` expressions are hashed
+/// into the seen-set while the full formatted message is still passed to
+/// the underlying `log!` call. The key expressions must be parenthesized so
+/// the parser can tell where the key group ends and the message begins.
+///
 /// The `max_level_*` features can be used to statically disable logging at
 /// various levels.
 #[macro_export]
@@ -100,11 +213,40 @@ macro_rules! log_once {
             &(*__SEEN_MESSAGES)
         }
     });
+    (target: $target:expr, $lvl:expr, key: ($($key:expr),+), $message:expr) => ({
+        #[allow(non_snake_case)]
+        let __SEEN_MESSAGES = log_once!(@CREATE STATIC);
+        let mut seen_messages = __SEEN_MESSAGES.lock().expect("Mutex was poisonned");
+        let event = String::from(stringify!($target)) + stringify!($lvl) $(+ &format!("{:?}", $key))+;
+        let event = $crate::__seen_key(&event);
+        if !seen_messages.contains(&event) {
+            seen_messages.insert(event);
+            log!(target: $target, $lvl, "{}", $message);
+        }
+    });
+    (target: $target:expr, $lvl:expr, key: ($($key:expr),+), $format:expr, $($arg:tt)+) => ({
+        #[allow(non_snake_case)]
+        let __SEEN_MESSAGES = log_once!(@CREATE STATIC);
+        let mut seen_messages = __SEEN_MESSAGES.lock().expect("Mutex was poisonned");
+        let event = String::from(stringify!($target)) + stringify!($lvl) $(+ &format!("{:?}", $key))+;
+        let event = $crate::__seen_key(&event);
+        if !seen_messages.contains(&event) {
+            seen_messages.insert(event);
+            log!(target: $target, $lvl, $format, $($arg)+);
+        }
+    });
+    ($lvl:expr, key: ($($key:expr),+), $message:expr) => (
+        log_once!(target: module_path!(), $lvl, key: ($($key),+), $message)
+    );
+    ($lvl:expr, key: ($($key:expr),+), $format:expr, $($arg:tt)+) => (
+        log_once!(target: module_path!(), $lvl, key: ($($key),+), $format, $($arg)+)
+    );
     (target: $target:expr, $lvl:expr, $message:expr) => ({
         #[allow(non_snake_case)]
         let __SEEN_MESSAGES = log_once!(@CREATE STATIC);
         let mut seen_messages = __SEEN_MESSAGES.lock().expect("Mutex was poisonned");
         let event = String::from(stringify!($target)) + stringify!($lvl) + $message.as_ref();
+        let event = $crate::__seen_key(&event);
         if !seen_messages.contains(&event) {
             seen_messages.insert(event);
             log!(target: $target, $lvl, "{}", $message);
@@ -219,6 +361,427 @@ macro_rules! trace_once {
     )
 }
 
+/// Standard logging macro, logging events once for each call site.
+///
+/// Unlike [`log_once!`](macro.log_once.html), which formats the message and
+/// checks it against a locked set of already-seen messages before deciding
+/// whether to log,
+/// `log_once_here!` deduplicates on the *call site* itself: each expansion
+/// declares its own `static AtomicBool`, and the format arguments are only
+/// evaluated the first time this particular invocation runs. The common,
+/// already-seen path is a single relaxed atomic swap, with no allocation
+/// and no lock contention.
+///
+/// This means varying the arguments at one call site will **not** produce
+/// new messages; use this when you only care whether a given code path has
+/// already fired, not about distinguishing between the different messages
+/// it could produce. Use [`log_once!`](macro.log_once.html) if you need the
+/// latter.
+#[macro_export]
+macro_rules! log_once_here {
+    (target: $target:expr, $lvl:expr, $($arg:tt)+) => ({
+        use ::std::sync::atomic::{AtomicBool, Ordering};
+        static SEEN: AtomicBool = AtomicBool::new(false);
+        if !SEEN.swap(true, Ordering::Relaxed) {
+            log!(target: $target, $lvl, $($arg)+);
+        }
+    });
+    ($lvl:expr, $($arg:tt)+) => (
+        log_once_here!(target: module_path!(), $lvl, $($arg)+);
+    );
+}
+
+/// Logs a message once per call site at the error level.
+///
+/// See [`log_once_here!`](macro.log_once_here.html) for the exact
+/// deduplication semantics.
+#[macro_export]
+macro_rules! error_once_here {
+    (target: $target:expr, $($arg:tt)*) => (
+        log_once_here!(target: $target, $crate::LogLevel::Error, $($arg)*);
+    );
+    ($($arg:tt)*) => (
+        log_once_here!($crate::LogLevel::Error, $($arg)*);
+    )
+}
+
+/// Logs a message once per call site at the warn level.
+///
+/// See [`log_once_here!`](macro.log_once_here.html) for the exact
+/// deduplication semantics.
+#[macro_export]
+macro_rules! warn_once_here {
+    (target: $target:expr, $($arg:tt)*) => (
+        log_once_here!(target: $target, $crate::LogLevel::Warn, $($arg)*);
+    );
+    ($($arg:tt)*) => (
+        log_once_here!($crate::LogLevel::Warn, $($arg)*);
+    )
+}
+
+/// Logs a message once per call site at the info level.
+///
+/// See [`log_once_here!`](macro.log_once_here.html) for the exact
+/// deduplication semantics.
+#[macro_export]
+macro_rules! info_once_here {
+    (target: $target:expr, $($arg:tt)*) => (
+        log_once_here!(target: $target, $crate::LogLevel::Info, $($arg)*);
+    );
+    ($($arg:tt)*) => (
+        log_once_here!($crate::LogLevel::Info, $($arg)*);
+    )
+}
+
+/// Logs a message once per call site at the debug level.
+///
+/// See [`log_once_here!`](macro.log_once_here.html) for the exact
+/// deduplication semantics.
+#[macro_export]
+macro_rules! debug_once_here {
+    (target: $target:expr, $($arg:tt)*) => (
+        log_once_here!(target: $target, $crate::LogLevel::Debug, $($arg)*);
+    );
+    ($($arg:tt)*) => (
+        log_once_here!($crate::LogLevel::Debug, $($arg)*);
+    )
+}
+
+/// Logs a message once per call site at the trace level.
+///
+/// See [`log_once_here!`](macro.log_once_here.html) for the exact
+/// deduplication semantics.
+#[macro_export]
+macro_rules! trace_once_here {
+    (target: $target:expr, $($arg:tt)*) => (
+        log_once_here!(target: $target, $crate::LogLevel::Trace, $($arg)*);
+    );
+    ($($arg:tt)*) => (
+        log_once_here!($crate::LogLevel::Trace, $($arg)*);
+    )
+}
+
+/// Standard logging macro, logging events once for each message, while
+/// keeping at most `capacity` messages remembered per call site.
+///
+/// This is [`log_once!`](macro.log_once.html) with a bounded memory
+/// footprint: once `capacity` distinct messages have been seen at this call
+/// site, inserting a new one evicts the oldest remembered message (FIFO).
+/// An evicted message may be logged again if it reoccurs later, which is
+/// the trade-off for not growing the seen-set forever on call sites whose
+/// messages have unbounded cardinality (ids, timestamps, addresses, ...).
+#[macro_export]
+macro_rules! log_once_bounded {
+    (@CREATE STATIC $capacity:expr) => ({
+        use ::std::sync::{Once, ONCE_INIT};
+        static mut __SEEN_MESSAGES: *const $crate::__MessagesSet = 0 as *const _;
+        static ONCE: Once = ONCE_INIT;
+        unsafe {
+            ONCE.call_once(|| {
+                let singleton = $crate::__MessagesSet::with_capacity(Some($capacity));
+                __SEEN_MESSAGES = ::std::mem::transmute(Box::new(singleton));
+            });
+            &(*__SEEN_MESSAGES)
+        }
+    });
+    (target: $target:expr, $lvl:expr, $capacity:expr, $message:expr) => ({
+        #[allow(non_snake_case)]
+        let __SEEN_MESSAGES = log_once_bounded!(@CREATE STATIC $capacity);
+        let mut seen_messages = __SEEN_MESSAGES.lock().expect("Mutex was poisonned");
+        let event = String::from(stringify!($target)) + stringify!($lvl) + $message.as_ref();
+        let event = $crate::__seen_key(&event);
+        if !seen_messages.contains(&event) {
+            seen_messages.insert(event);
+            log!(target: $target, $lvl, "{}", $message);
+        }
+    });
+    (target: $target:expr, $lvl:expr, $capacity:expr, $format:expr, $($arg:tt)+) => ({
+        let message = format!($format, $($arg)+);
+        log_once_bounded!(target: $target, $lvl, $capacity, message);
+    });
+    ($lvl:expr, $capacity:expr, $message:expr) => (log_once_bounded!(target: module_path!(), $lvl, $capacity, $message));
+    ($lvl:expr, $capacity:expr, $format:expr, $($arg:tt)+) => (log_once_bounded!(target: module_path!(), $lvl, $capacity, $format, $($arg)+));
+}
+
+/// Logs a message once for each message at the error level, keeping at
+/// most `capacity` messages remembered per call site.
+///
+/// See [`log_once_bounded!`](macro.log_once_bounded.html) for the exact
+/// eviction semantics.
+#[macro_export]
+macro_rules! error_once_bounded {
+    (target: $target:expr, $capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!(target: $target, $crate::LogLevel::Error, $capacity, $($arg)*);
+    );
+    ($capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!($crate::LogLevel::Error, $capacity, $($arg)*);
+    )
+}
+
+/// Logs a message once for each message at the warn level, keeping at
+/// most `capacity` messages remembered per call site.
+///
+/// See [`log_once_bounded!`](macro.log_once_bounded.html) for the exact
+/// eviction semantics.
+#[macro_export]
+macro_rules! warn_once_bounded {
+    (target: $target:expr, $capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!(target: $target, $crate::LogLevel::Warn, $capacity, $($arg)*);
+    );
+    ($capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!($crate::LogLevel::Warn, $capacity, $($arg)*);
+    )
+}
+
+/// Logs a message once for each message at the info level, keeping at
+/// most `capacity` messages remembered per call site.
+///
+/// See [`log_once_bounded!`](macro.log_once_bounded.html) for the exact
+/// eviction semantics.
+#[macro_export]
+macro_rules! info_once_bounded {
+    (target: $target:expr, $capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!(target: $target, $crate::LogLevel::Info, $capacity, $($arg)*);
+    );
+    ($capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!($crate::LogLevel::Info, $capacity, $($arg)*);
+    )
+}
+
+/// Logs a message once for each message at the debug level, keeping at
+/// most `capacity` messages remembered per call site.
+///
+/// See [`log_once_bounded!`](macro.log_once_bounded.html) for the exact
+/// eviction semantics.
+#[macro_export]
+macro_rules! debug_once_bounded {
+    (target: $target:expr, $capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!(target: $target, $crate::LogLevel::Debug, $capacity, $($arg)*);
+    );
+    ($capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!($crate::LogLevel::Debug, $capacity, $($arg)*);
+    )
+}
+
+/// Logs a message once for each message at the trace level, keeping at
+/// most `capacity` messages remembered per call site.
+///
+/// See [`log_once_bounded!`](macro.log_once_bounded.html) for the exact
+/// eviction semantics.
+#[macro_export]
+macro_rules! trace_once_bounded {
+    (target: $target:expr, $capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!(target: $target, $crate::LogLevel::Trace, $capacity, $($arg)*);
+    );
+    ($capacity:expr, $($arg:tt)*) => (
+        log_once_bounded!($crate::LogLevel::Trace, $capacity, $($arg)*);
+    )
+}
+
+lazy_static! {
+    #[doc(hidden)]
+    pub static ref __EPOCH: ::std::time::Instant = ::std::time::Instant::now();
+}
+
+/// Standard logging macro, logging events once every `n` calls to a given
+/// call site.
+///
+/// Like [`log_once_here!`](macro.log_once_here.html), this deduplicates per
+/// call site rather than per message, using a `static AtomicUsize` counter
+/// instead of a `static AtomicBool`: the first call is always logged, and
+/// every `n`-th call after that is logged again.
+///
+/// `n` must be greater than zero; this is checked with an `assert!` on every
+/// call, since `count % n` would otherwise panic with a division by zero.
+#[macro_export]
+macro_rules! log_every_n {
+    (target: $target:expr, $lvl:expr, $n:expr, $($arg:tt)+) => ({
+        use ::std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let n = $n;
+        assert!(n > 0, "log_every_n! requires n > 0");
+        let count = COUNT.fetch_add(1, Ordering::Relaxed);
+        if count % n == 0 {
+            log!(target: $target, $lvl, $($arg)+);
+        }
+    });
+    ($lvl:expr, $n:expr, $($arg:tt)+) => (
+        log_every_n!(target: module_path!(), $lvl, $n, $($arg)+);
+    );
+}
+
+/// Logs a message every `n` calls to this call site, at the error level.
+///
+/// See [`log_every_n!`](macro.log_every_n.html) for the exact semantics.
+#[macro_export]
+macro_rules! error_every_n {
+    (target: $target:expr, $n:expr, $($arg:tt)*) => (
+        log_every_n!(target: $target, $crate::LogLevel::Error, $n, $($arg)*);
+    );
+    ($n:expr, $($arg:tt)*) => (
+        log_every_n!($crate::LogLevel::Error, $n, $($arg)*);
+    )
+}
+
+/// Logs a message every `n` calls to this call site, at the warn level.
+///
+/// See [`log_every_n!`](macro.log_every_n.html) for the exact semantics.
+#[macro_export]
+macro_rules! warn_every_n {
+    (target: $target:expr, $n:expr, $($arg:tt)*) => (
+        log_every_n!(target: $target, $crate::LogLevel::Warn, $n, $($arg)*);
+    );
+    ($n:expr, $($arg:tt)*) => (
+        log_every_n!($crate::LogLevel::Warn, $n, $($arg)*);
+    )
+}
+
+/// Logs a message every `n` calls to this call site, at the info level.
+///
+/// See [`log_every_n!`](macro.log_every_n.html) for the exact semantics.
+#[macro_export]
+macro_rules! info_every_n {
+    (target: $target:expr, $n:expr, $($arg:tt)*) => (
+        log_every_n!(target: $target, $crate::LogLevel::Info, $n, $($arg)*);
+    );
+    ($n:expr, $($arg:tt)*) => (
+        log_every_n!($crate::LogLevel::Info, $n, $($arg)*);
+    )
+}
+
+/// Logs a message every `n` calls to this call site, at the debug level.
+///
+/// See [`log_every_n!`](macro.log_every_n.html) for the exact semantics.
+#[macro_export]
+macro_rules! debug_every_n {
+    (target: $target:expr, $n:expr, $($arg:tt)*) => (
+        log_every_n!(target: $target, $crate::LogLevel::Debug, $n, $($arg)*);
+    );
+    ($n:expr, $($arg:tt)*) => (
+        log_every_n!($crate::LogLevel::Debug, $n, $($arg)*);
+    )
+}
+
+/// Logs a message every `n` calls to this call site, at the trace level.
+///
+/// See [`log_every_n!`](macro.log_every_n.html) for the exact semantics.
+#[macro_export]
+macro_rules! trace_every_n {
+    (target: $target:expr, $n:expr, $($arg:tt)*) => (
+        log_every_n!(target: $target, $crate::LogLevel::Trace, $n, $($arg)*);
+    );
+    ($n:expr, $($arg:tt)*) => (
+        log_every_n!($crate::LogLevel::Trace, $n, $($arg)*);
+    )
+}
+
+/// Standard logging macro, logging events at most once per `interval`, per
+/// call site.
+///
+/// Like [`log_every_n!`](macro.log_every_n.html), this deduplicates per call
+/// site using a `static AtomicU64` holding the last emission time, in whole
+/// seconds since an internal `lazy_static` epoch (the first time any
+/// `..._once_per!` call site runs). The first call always logs; later calls
+/// log again once this whole-second timestamp has advanced by at least
+/// `interval`. Because both timestamps are truncated to whole seconds, the
+/// real time between two emissions can be up to one second shorter than
+/// `interval`. The timestamp is updated
+/// with a compare-and-swap so that concurrent callers racing past the
+/// deadline don't double-emit.
+///
+/// `interval` is rounded down to whole seconds, so it must be at least one
+/// second; this is checked with an `assert!` on every call, since a
+/// sub-second interval would otherwise make `interval_secs == 0` and the
+/// throttle a silent no-op (every call would log).
+#[macro_export]
+macro_rules! log_once_per {
+    (target: $target:expr, $lvl:expr, $interval:expr, $($arg:tt)+) => ({
+        use ::std::sync::atomic::{AtomicU64, Ordering};
+        static LAST: AtomicU64 = AtomicU64::new(::std::u64::MAX);
+        let interval_secs = $interval.as_secs();
+        assert!(interval_secs > 0, "log_once_per! requires an interval of at least one second");
+        let now = $crate::__EPOCH.elapsed().as_secs();
+        let last = LAST.load(Ordering::Relaxed);
+        let due = last == ::std::u64::MAX || now.saturating_sub(last) >= interval_secs;
+        if due && LAST.compare_and_swap(last, now, Ordering::Relaxed) == last {
+            log!(target: $target, $lvl, $($arg)+);
+        }
+    });
+    ($lvl:expr, $interval:expr, $($arg:tt)+) => (
+        log_once_per!(target: module_path!(), $lvl, $interval, $($arg)+);
+    );
+}
+
+/// Logs a message at most once per `interval`, per call site, at the error
+/// level.
+///
+/// See [`log_once_per!`](macro.log_once_per.html) for the exact semantics.
+#[macro_export]
+macro_rules! error_once_per {
+    (target: $target:expr, $interval:expr, $($arg:tt)*) => (
+        log_once_per!(target: $target, $crate::LogLevel::Error, $interval, $($arg)*);
+    );
+    ($interval:expr, $($arg:tt)*) => (
+        log_once_per!($crate::LogLevel::Error, $interval, $($arg)*);
+    )
+}
+
+/// Logs a message at most once per `interval`, per call site, at the warn
+/// level.
+///
+/// See [`log_once_per!`](macro.log_once_per.html) for the exact semantics.
+#[macro_export]
+macro_rules! warn_once_per {
+    (target: $target:expr, $interval:expr, $($arg:tt)*) => (
+        log_once_per!(target: $target, $crate::LogLevel::Warn, $interval, $($arg)*);
+    );
+    ($interval:expr, $($arg:tt)*) => (
+        log_once_per!($crate::LogLevel::Warn, $interval, $($arg)*);
+    )
+}
+
+/// Logs a message at most once per `interval`, per call site, at the info
+/// level.
+///
+/// See [`log_once_per!`](macro.log_once_per.html) for the exact semantics.
+#[macro_export]
+macro_rules! info_once_per {
+    (target: $target:expr, $interval:expr, $($arg:tt)*) => (
+        log_once_per!(target: $target, $crate::LogLevel::Info, $interval, $($arg)*);
+    );
+    ($interval:expr, $($arg:tt)*) => (
+        log_once_per!($crate::LogLevel::Info, $interval, $($arg)*);
+    )
+}
+
+/// Logs a message at most once per `interval`, per call site, at the debug
+/// level.
+///
+/// See [`log_once_per!`](macro.log_once_per.html) for the exact semantics.
+#[macro_export]
+macro_rules! debug_once_per {
+    (target: $target:expr, $interval:expr, $($arg:tt)*) => (
+        log_once_per!(target: $target, $crate::LogLevel::Debug, $interval, $($arg)*);
+    );
+    ($interval:expr, $($arg:tt)*) => (
+        log_once_per!($crate::LogLevel::Debug, $interval, $($arg)*);
+    )
+}
+
+/// Logs a message at most once per `interval`, per call site, at the trace
+/// level.
+///
+/// See [`log_once_per!`](macro.log_once_per.html) for the exact semantics.
+#[macro_export]
+macro_rules! trace_once_per {
+    (target: $target:expr, $interval:expr, $($arg:tt)*) => (
+        log_once_per!(target: $target, $crate::LogLevel::Trace, $interval, $($arg)*);
+    );
+    ($interval:expr, $($arg:tt)*) => (
+        log_once_per!($crate::LogLevel::Trace, $interval, $($arg)*);
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::Cell;
@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate log_once;
+#[macro_use]
+extern crate lazy_static;
+
+mod logger;
+
+#[test]
+fn bounded_eviction() {
+    logger::init();
+
+    // With a capacity of 2, re-logging the first message after two other
+    // messages have pushed it out of the seen-set should log it again. The
+    // seen-set belongs to the call site, so all messages go through one.
+    for i in &[0, 1, 2, 0] {
+        info_once_bounded!(2, "message {}", i);
+    }
+
+    // The global logger is shared with the other test in this binary, so
+    // only compare the lines this test logs.
+    let data: String = logger::logged_data().lines()
+        .filter(|line| line.starts_with("message "))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    let expected =
+"message 0
+message 1
+message 2
+message 0
+";
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn bounded_zero_capacity_remembers_nothing() {
+    logger::init();
+
+    // A capacity of 0 means "never remember anything", so the exact same
+    // message logged twice should be logged both times.
+    for _ in 0..2 {
+        info_once_bounded!(0, "msg {}", 1);
+    }
+
+    // The global logger is shared with other tests in this binary, so check
+    // for the expected lines rather than asserting exact equality.
+    let data = logger::logged_data();
+    let expected =
+"msg 1
+msg 1
+";
+    assert!(data.contains(expected), "{:?} does not contain {:?}", data, expected);
+}
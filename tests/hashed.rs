@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate log_once;
+#[macro_use]
+extern crate lazy_static;
+
+mod logger;
+
+#[test]
+fn hashed_dedup() {
+    logger::init();
+
+    for _ in 0..4 {
+        warn_once!("This one is only logged once {}", 43);
+    }
+
+    for i in 0..4 {
+        warn_once!("This will be logged twice {}", i % 2);
+    }
+
+    let data = logger::logged_data();
+    let expected =
+"This one is only logged once 43
+This will be logged twice 0
+This will be logged twice 1
+";
+    assert_eq!(data, expected);
+}
@@ -0,0 +1,24 @@
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate log_once;
+#[macro_use]
+extern crate lazy_static;
+
+mod logger;
+
+#[test]
+fn keyed() {
+    logger::init();
+
+    for conn_id in &[1, 1, 2, 1] {
+        warn_once!(key: (conn_id), "connection {} dropped: {}", conn_id, conn_id * 10);
+    }
+
+    let data = logger::logged_data();
+    let expected =
+"connection 1 dropped: 10
+connection 2 dropped: 20
+";
+    assert_eq!(data, expected);
+}
@@ -1,4 +1,4 @@
-use log::{Record, LevelFilter, Metadata};
+use log::{LogRecord, LogLevelFilter, LogMetadata};
 use std::sync::{Mutex, Once, ONCE_INIT};
 use std::fmt::Write;
 
@@ -10,25 +10,23 @@ lazy_static!{
 pub struct MemoryLogger;
 
 impl ::log::Log for MemoryLogger {
-    fn enabled(&self, _: &Metadata) -> bool {
+    fn enabled(&self, _: &LogMetadata) -> bool {
         true
     }
 
-    fn log(&self, record: &Record) {
+    fn log(&self, record: &LogRecord) {
         let mut buffer = LOGGED_DATA.lock().expect("Mutex has been poisonned");
         writeln!(*buffer, "{}", record.args()).expect("Error while writing");
     }
-
-    fn flush(&self) {}
 }
 
-static LOGGER: MemoryLogger = MemoryLogger;
-
 pub fn init() {
     static START: Once = ONCE_INIT;
     START.call_once(|| {
-        ::log::set_logger(&LOGGER).expect("Could not set the logger");
-        ::log::set_max_level(LevelFilter::Trace);
+        ::log::set_logger(|max_log_level| {
+            max_log_level.set(LogLevelFilter::Trace);
+            Box::new(MemoryLogger)
+        }).expect("Could not set the logger");
     });
 }
 
@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate log_once;
+#[macro_use]
+extern crate lazy_static;
+
+mod logger;
+
+#[test]
+fn once_here() {
+    logger::init();
+
+    for i in 0..4 {
+        info_once_here!("This is logged once, whatever the argument {}", i);
+    }
+
+    let data = logger::logged_data();
+    let expected = "This is logged once, whatever the argument 0\n";
+    assert_eq!(data, expected);
+}
@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate log_once;
+#[macro_use]
+extern crate lazy_static;
+
+mod logger;
+
+#[test]
+fn once_per() {
+    logger::init();
+
+    for i in 0..3 {
+        warn_once_per!(::std::time::Duration::from_secs(1), "Throttled message {}", i);
+        if i == 0 {
+            ::std::thread::sleep(::std::time::Duration::from_millis(1100));
+        }
+    }
+
+    let data = logger::logged_data();
+    let expected =
+"Throttled message 0
+Throttled message 1
+";
+    assert_eq!(data, expected);
+}
+
+#[test]
+#[should_panic(expected = "log_once_per! requires an interval of at least one second")]
+fn once_per_sub_second_interval_panics() {
+    logger::init();
+    warn_once_per!(::std::time::Duration::from_millis(250), "Should never log");
+}
@@ -0,0 +1,24 @@
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate log_once;
+#[macro_use]
+extern crate lazy_static;
+
+mod logger;
+
+#[test]
+fn every_n() {
+    logger::init();
+
+    for i in 0..6 {
+        info_every_n!(3, "Every third call, iteration {}", i);
+    }
+
+    let data = logger::logged_data();
+    let expected =
+"Every third call, iteration 0
+Every third call, iteration 3
+";
+    assert_eq!(data, expected);
+}